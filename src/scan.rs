@@ -1,30 +1,157 @@
 use crate::structs::{Characteristic, DeviceInfo};
 use btleplug::api::{
-    Central, CentralEvent, Manager as _, Peripheral, PeripheralProperties, ScanFilter,
+    CharPropFlags, Central, CentralEvent, Characteristic as BtleCharacteristic, Manager as _,
+    Peripheral, PeripheralProperties, ScanFilter, WriteType,
 };
-use btleplug::platform::Manager;
-use futures::StreamExt;
+use async_trait::async_trait;
+use btleplug::platform::{Adapter, Manager, PeripheralId};
+use futures::{Stream, StreamExt};
+use serde::Deserialize;
+use std::collections::HashMap;
 use std::error::Error;
+use std::pin::Pin;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc;
 use tokio::time::timeout;
+use uuid::Uuid;
+
+/// Filtering options applied while scanning for Bluetooth devices.
+///
+/// The advertised `services` are passed straight through to btleplug's
+/// [`ScanFilter`] so the controller itself drops uninteresting advertisements,
+/// while the remaining fields are evaluated client-side for every advertisement
+/// before a device is reported.
+#[derive(Debug, Clone, Default)]
+pub struct ScanOptions {
+    /// Advertised service UUIDs to filter on at the controller level.
+    pub services: Vec<Uuid>,
+    /// Only report devices whose local name starts with this prefix.
+    pub name_prefix: Option<String>,
+    /// Only report devices whose local name contains this substring.
+    pub name_contains: Option<String>,
+    /// Allowlist of manufacturer company identifiers (the first two LE bytes of
+    /// each `manufacturer_data` key). An empty list allows any company.
+    pub company_ids: Vec<u16>,
+    /// Minimum RSSI, in dBm, a device must advertise to be reported.
+    pub min_rssi: Option<i16>,
+}
+
+/// Connection state tracked for each discovered device, mirroring the ACL
+/// state the platform Bluetooth stack maintains.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// The device is currently advertising / reachable.
+    Connected,
+    /// A reconnection attempt is in flight.
+    Connecting,
+    /// The device was seen previously but has since disconnected.
+    Disconnected,
+    /// Reconnection was abandoned after exhausting the attempt budget.
+    Lost,
+}
+
+impl ScanOptions {
+    /// Returns `true` if a peripheral with these `properties` passes every
+    /// client-side predicate.
+    fn matches(&self, properties: &PeripheralProperties) -> bool {
+        if let Some(prefix) = &self.name_prefix {
+            match &properties.local_name {
+                Some(name) if name.starts_with(prefix) => {}
+                _ => return false,
+            }
+        }
+        if let Some(needle) = &self.name_contains {
+            match &properties.local_name {
+                Some(name) if name.contains(needle) => {}
+                _ => return false,
+            }
+        }
+        if !self.company_ids.is_empty()
+            && !properties
+                .manufacturer_data
+                .keys()
+                .any(|id| self.company_ids.contains(id))
+        {
+            return false;
+        }
+        if let Some(min) = self.min_rssi {
+            match properties.rssi {
+                Some(rssi) if rssi >= min => {}
+                _ => return false,
+            }
+        }
+        true
+    }
+}
+
+/// Lists the available Bluetooth adapters as human-readable descriptors, in the
+/// same order they can be selected by index in [`bluetooth_scan`].
+pub async fn list_adapters() -> Result<Vec<String>, Box<dyn Error>> {
+    let manager = Manager::new().await?;
+    let mut descriptors = Vec::new();
+    for adapter in manager.adapters().await? {
+        descriptors.push(adapter.adapter_info().await?);
+    }
+    Ok(descriptors)
+}
+
+/// Best-effort power-cycle used to recover a wedged controller (the classic
+/// "connection refused, works with gatttool" situation): the in-flight scan is
+/// stopped and restarted, which is the extent of the control btleplug exposes
+/// portably.
+async fn power_cycle(central: &Adapter, options: &ScanOptions) -> Result<(), Box<dyn Error>> {
+    let _ = central.stop_scan().await;
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    central
+        .start_scan(ScanFilter {
+            services: options.services.clone(),
+        })
+        .await?;
+    Ok(())
+}
 
 /// Scans for Bluetooth devices and sends the information to the provided `mpsc::Sender`.
 /// The scan can be paused by setting the `pause_signal` to `true`.
+/// Advertisements are filtered according to the supplied [`ScanOptions`].
+/// `adapter_index` selects which adapter from [`list_adapters`] to scan with.
 pub async fn bluetooth_scan(
     tx: mpsc::Sender<Vec<DeviceInfo>>,
     pause_signal: Arc<AtomicBool>,
+    options: ScanOptions,
+    adapter_index: usize,
 ) -> Result<(), Box<dyn Error>> {
     let manager = Manager::new().await?;
-    let adapters = manager.adapters().await?;
-    let central = adapters.into_iter().next().ok_or("No adapters found")?;
+    let central = manager
+        .adapters()
+        .await?
+        .into_iter()
+        .nth(adapter_index)
+        .ok_or("No adapters found")?;
 
-    central.start_scan(ScanFilter::default()).await?;
-    let mut events = central.events().await?;
+    let backend = BtleplugBackend::with_adapter(central);
+    run_scan(&backend, tx, pause_signal, options).await
+}
+
+/// Backend-agnostic scan loop shared by the real adapter and the mock.
+///
+/// Devices are keyed by their id so repeated advertisements refresh the existing
+/// entry in place rather than appending a new row every time; each accumulated
+/// snapshot is sent on `tx` after every processed event.
+async fn run_scan<B: ScanBackend + ?Sized>(
+    backend: &B,
+    tx: mpsc::Sender<Vec<DeviceInfo>>,
+    pause_signal: Arc<AtomicBool>,
+    options: ScanOptions,
+) -> Result<(), Box<dyn Error>> {
+    backend.start_scan(&options).await?;
+    let mut events = backend.events().await?;
 
-    let mut devices_info = Vec::new();
+    // Keep an insertion-ordered list of rows plus an id -> position index so
+    // advertisements refresh a device in place without reshuffling the table.
+    let mut devices_info: Vec<DeviceInfo> = Vec::new();
+    let mut positions: HashMap<String, usize> = HashMap::new();
 
     while let Some(event) = events.next().await {
         // Check the pause signal before processing the event
@@ -32,30 +159,46 @@ pub async fn bluetooth_scan(
             tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
         }
 
-        if let CentralEvent::DeviceDiscovered(id) = event {
-            if let Ok(device) = central.peripheral(&id).await {
-                let properties = device
-                    .properties()
-                    .await?
-                    .unwrap_or(PeripheralProperties::default());
-
-                // Add the new device's information to the accumulated list
-                devices_info.push(DeviceInfo::new(
-                    device.id().to_string(),
-                    properties.local_name,
-                    properties.tx_power_level,
-                    properties.address.to_string(),
-                    properties.rssi,
-                    properties.manufacturer_data,
-                    properties.services,
-                    properties.service_data,
-                    device.clone(),
-                ));
-
-                // Send a clone of the accumulated device information so far
-                tx.send(devices_info.clone()).await?;
+        match event {
+            ScanEvent::DeviceDiscovered(id) | ScanEvent::DeviceUpdated(id) => {
+                if let Some(properties) = backend.properties(&id).await? {
+                    // Skip advertisements that do not match the client-side filters
+                    if !options.matches(&properties) {
+                        continue;
+                    }
+
+                    let info = DeviceInfo::new(
+                        id.clone(),
+                        properties.local_name,
+                        properties.tx_power_level,
+                        properties.address.to_string(),
+                        properties.rssi,
+                        properties.manufacturer_data,
+                        properties.services,
+                        properties.service_data,
+                        backend.peripheral_handle(&id).await,
+                    );
+                    // Refresh the latest advertised data in place, preserving the
+                    // row's original position for devices seen before.
+                    match positions.get(&id) {
+                        Some(&index) => devices_info[index] = info,
+                        None => {
+                            positions.insert(id, devices_info.len());
+                            devices_info.push(info);
+                        }
+                    }
+                }
+            }
+            ScanEvent::DeviceDisconnected(id) => {
+                // Keep the row around but flag it so the UI can show the loss
+                if let Some(&index) = positions.get(&id) {
+                    devices_info[index].connection_state = ConnectionState::Disconnected;
+                }
             }
         }
+
+        // Send a snapshot of the accumulated device information so far
+        tx.send(devices_info.clone()).await?;
     }
 
     Ok(())
@@ -67,11 +210,27 @@ pub async fn get_characteristics(
     peripheral: &btleplug::platform::Peripheral,
 ) -> Result<Vec<Characteristic>, Box<dyn Error>> {
     let duration = Duration::from_secs(10);
-    timeout(duration, peripheral.connect()).await??;
+    // A wedged controller often refuses or hangs the first connection; on a
+    // failure *or a timeout* (the "connection refused / works with gatttool"
+    // case), reset the link (disconnect) and retry once before bubbling out.
+    if timeout(duration, peripheral.connect())
+        .await
+        .map_or(true, |result| result.is_err())
+    {
+        let _ = peripheral.disconnect().await;
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        timeout(duration, peripheral.connect()).await??;
+    }
 
     let characteristics = peripheral.characteristics();
     let mut result = Vec::new();
     for characteristic in characteristics {
+        // Never expose fully blocklisted attributes to the UI
+        if uuid_is_blocklisted(characteristic.uuid, Blocklist::All)
+            || uuid_is_blocklisted(characteristic.service_uuid, Blocklist::All)
+        {
+            continue;
+        }
         result.push(Characteristic {
             uuid: characteristic.uuid,
             properties: characteristic.properties,
@@ -85,3 +244,618 @@ pub async fn get_characteristics(
     }
     Ok(result)
 }
+
+/// Access level at which a GATT attribute is excluded, mirroring the Web
+/// Bluetooth GATT blocklist. `All` forbids every operation on the attribute,
+/// while `Reads` and `Writes` forbid only that form of access.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Blocklist {
+    All,
+    Reads,
+    Writes,
+}
+
+/// Known security-sensitive UUIDs and the access level at which they are
+/// excluded. Seeded from the Web Bluetooth GATT blocklist.
+static BLOCKLIST: &[(&str, Blocklist)] = &[
+    // Services
+    ("00001812-0000-1000-8000-00805f9b34fb", Blocklist::All), // Human Interface Device
+    ("00001530-1212-efde-1523-785feabcd123", Blocklist::All), // Nordic DFU
+    ("f000ffc0-0451-4000-b000-000000000000", Blocklist::All), // TI Over-the-Air Download
+    // Characteristics
+    ("00002a02-0000-1000-8000-00805f9b34fb", Blocklist::Writes), // Peripheral Privacy Flag
+    ("00002a03-0000-1000-8000-00805f9b34fb", Blocklist::All),    // Reconnection Address
+    ("00002a25-0000-1000-8000-00805f9b34fb", Blocklist::All),    // Serial Number String
+    // Descriptors
+    ("00002902-0000-1000-8000-00805f9b34fb", Blocklist::Writes), // Client Characteristic Configuration
+    ("00002903-0000-1000-8000-00805f9b34fb", Blocklist::Writes), // Server Characteristic Configuration
+];
+
+/// Returns the exclusion level recorded for `uuid`, if any.
+fn blocklist_level(uuid: Uuid) -> Option<Blocklist> {
+    let uuid = uuid.to_string();
+    BLOCKLIST
+        .iter()
+        .find(|(entry, _)| *entry == uuid)
+        .map(|(_, level)| *level)
+}
+
+/// Returns `true` if `uuid` is blocklisted for the requested `which` access.
+///
+/// An `All`-blocklisted UUID is reported for every access, while a
+/// `Reads`/`Writes` entry only matches the corresponding operation.
+pub fn uuid_is_blocklisted(uuid: Uuid, which: Blocklist) -> bool {
+    match blocklist_level(uuid) {
+        Some(Blocklist::All) => true,
+        Some(level) => level == which,
+        None => false,
+    }
+}
+
+/// Looks up the GATT characteristic with the given `uuid` on an already
+/// connected peripheral.
+fn find_characteristic(
+    peripheral: &btleplug::platform::Peripheral,
+    uuid: Uuid,
+) -> Result<BtleCharacteristic, Box<dyn Error>> {
+    peripheral
+        .characteristics()
+        .into_iter()
+        .find(|c| c.uuid == uuid)
+        .ok_or_else(|| format!("Characteristic {uuid} not found").into())
+}
+
+/// Reads the current value of the characteristic identified by `uuid`.
+pub async fn read_characteristic(
+    peripheral: &btleplug::platform::Peripheral,
+    uuid: Uuid,
+) -> Result<Vec<u8>, Box<dyn Error>> {
+    if uuid_is_blocklisted(uuid, Blocklist::Reads) {
+        return Err(format!("Reading characteristic {uuid} is blocklisted").into());
+    }
+    let characteristic = find_characteristic(peripheral, uuid)?;
+    let value = peripheral.read(&characteristic).await?;
+    Ok(value)
+}
+
+/// Writes `data` to the characteristic identified by `uuid`.
+///
+/// Write-with-response is used when the characteristic advertises the `WRITE`
+/// property, otherwise an unacknowledged write-without-response is issued.
+pub async fn write_characteristic(
+    peripheral: &btleplug::platform::Peripheral,
+    uuid: Uuid,
+    data: &[u8],
+) -> Result<(), Box<dyn Error>> {
+    if uuid_is_blocklisted(uuid, Blocklist::Writes) {
+        return Err(format!("Writing characteristic {uuid} is blocklisted").into());
+    }
+    let characteristic = find_characteristic(peripheral, uuid)?;
+    let write_type = if characteristic.properties.contains(CharPropFlags::WRITE) {
+        WriteType::WithResponse
+    } else {
+        WriteType::WithoutResponse
+    };
+    peripheral.write(&characteristic, data, write_type).await?;
+    Ok(())
+}
+
+/// Subscribes to notifications/indications from the characteristic identified by
+/// `uuid` and returns an async stream of `(Uuid, Vec<u8>)` values so the TUI can
+/// render live sensor data.
+pub async fn subscribe_characteristic(
+    peripheral: &btleplug::platform::Peripheral,
+    uuid: Uuid,
+) -> Result<impl Stream<Item = (Uuid, Vec<u8>)>, Box<dyn Error>> {
+    let characteristic = find_characteristic(peripheral, uuid)?;
+    peripheral.subscribe(&characteristic).await?;
+    // `peripheral.notifications()` emits for every subscribed characteristic, so
+    // restrict the stream to the one the caller asked about.
+    let notifications = peripheral.notifications().await?;
+    Ok(notifications
+        .filter(move |notification| futures::future::ready(notification.uuid == uuid))
+        .map(|notification| (notification.uuid, notification.value)))
+}
+
+/// Governs how aggressively [`maintain_connection`] retries after a device
+/// drops off. Delays grow exponentially from `base_delay`, capped at
+/// `max_delay`, for at most `max_attempts` tries.
+#[derive(Debug, Clone)]
+pub struct ReconnectPolicy {
+    /// Maximum number of reconnection attempts before giving up.
+    pub max_attempts: u32,
+    /// Delay before the first attempt; doubled on every subsequent attempt.
+    pub base_delay: Duration,
+    /// Upper bound on the backoff delay.
+    pub max_delay: Duration,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Watches a previously connected peripheral and transparently re-acquires it
+/// by `id` after a disconnect, retrying [`Peripheral::connect`] with exponential
+/// backoff until it succeeds or `policy`'s attempt budget is exhausted.
+///
+/// Backoff is only entered once a [`CentralEvent::DeviceDisconnected`] matching
+/// `id` has been observed; while the device stays connected the stream simply
+/// waits and yields nothing. The returned stream reports the connection
+/// lifecycle so the UI can render connecting/connected/lost transitions.
+pub async fn maintain_connection(
+    central: Adapter,
+    id: PeripheralId,
+    policy: ReconnectPolicy,
+) -> impl Stream<Item = ConnectionState> {
+    struct State {
+        central: Adapter,
+        id: PeripheralId,
+        policy: ReconnectPolicy,
+        events: Option<Pin<Box<dyn Stream<Item = CentralEvent> + Send>>>,
+        attempt: u32,
+        disconnected: bool,
+        done: bool,
+    }
+
+    let events = central
+        .events()
+        .await
+        .ok()
+        .map(|events| Box::pin(events) as Pin<Box<dyn Stream<Item = CentralEvent> + Send>>);
+
+    let state = State {
+        central,
+        id,
+        policy,
+        events,
+        attempt: 0,
+        disconnected: false,
+        done: false,
+    };
+
+    futures::stream::unfold(state, |mut state| async move {
+        if state.done {
+            return None;
+        }
+
+        // Until the matching disconnect is seen, stay parked on the event
+        // stream instead of issuing spurious reconnects.
+        if !state.disconnected {
+            let events = match state.events.as_mut() {
+                Some(events) => events,
+                None => {
+                    state.done = true;
+                    return Some((ConnectionState::Lost, state));
+                }
+            };
+            while let Some(event) = events.next().await {
+                if let CentralEvent::DeviceDisconnected(id) = event {
+                    if id == state.id {
+                        state.disconnected = true;
+                        return Some((ConnectionState::Disconnected, state));
+                    }
+                }
+            }
+            // The adapter stopped producing events; nothing more to do.
+            state.done = true;
+            return None;
+        }
+
+        state.attempt += 1;
+        if state.attempt > state.policy.max_attempts {
+            state.done = true;
+            return Some((ConnectionState::Lost, state));
+        }
+
+        // Exponential backoff, capped at `max_delay`. The shift is computed with
+        // a checked left-shift so a large attempt budget cannot overflow before
+        // `saturating_mul`/`min` have a chance to clamp it.
+        let factor = 1u32.checked_shl(state.attempt - 1).unwrap_or(u32::MAX);
+        let delay = state
+            .policy
+            .base_delay
+            .saturating_mul(factor)
+            .min(state.policy.max_delay);
+        tokio::time::sleep(delay).await;
+
+        // Re-query the adapter for the same id and retry the connection.
+        match state.central.peripheral(&state.id).await {
+            Ok(peripheral) if peripheral.connect().await.is_ok() => {
+                state.done = true;
+                Some((ConnectionState::Connected, state))
+            }
+            _ => Some((ConnectionState::Connecting, state)),
+        }
+    })
+}
+
+/// Adapter-agnostic scan events surfaced by a [`ScanBackend`].
+///
+/// btleplug's own `CentralEvent`/`PeripheralId` are opaque and cannot be
+/// fabricated, so the backend abstraction speaks in these string-keyed events
+/// that both the real adapter and the mock can produce.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScanEvent {
+    /// A device was seen for the first time.
+    DeviceDiscovered(String),
+    /// An already-known device re-advertised with fresh data.
+    DeviceUpdated(String),
+    /// A device dropped off.
+    DeviceDisconnected(String),
+}
+
+/// The minimal set of adapter operations the scanner relies on.
+///
+/// Implemented for the real btleplug adapter ([`BtleplugBackend`]) and for an
+/// offline [`MockBackend`] so the filter/blocklist logic and the TUI can be
+/// driven deterministically without hardware.
+#[async_trait]
+pub trait ScanBackend {
+    /// Human-readable descriptors for every available adapter.
+    async fn list_adapters(&self) -> Result<Vec<String>, Box<dyn Error>>;
+    /// Begin scanning with the controller-level filter derived from `options`.
+    async fn start_scan(&self, options: &ScanOptions) -> Result<(), Box<dyn Error>>;
+    /// Stream of scan events produced by the adapter.
+    async fn events(&self) -> Result<Pin<Box<dyn Stream<Item = ScanEvent> + Send>>, Box<dyn Error>>;
+    /// Advertised properties for the device with the given id.
+    async fn properties(&self, id: &str) -> Result<Option<PeripheralProperties>, Box<dyn Error>>;
+    /// Connect to the device with the given id.
+    async fn connect(&self, id: &str) -> Result<(), Box<dyn Error>>;
+    /// Characteristics discovered on a connected device.
+    async fn characteristics(&self, id: &str) -> Result<Vec<Characteristic>, Box<dyn Error>>;
+    /// Live peripheral handle for GATT operations, or `None` for backends
+    /// without real hardware (the mock).
+    async fn peripheral_handle(&self, id: &str) -> Option<btleplug::platform::Peripheral>;
+}
+
+/// [`ScanBackend`] backed by a real btleplug adapter.
+pub struct BtleplugBackend {
+    adapter: Adapter,
+}
+
+impl BtleplugBackend {
+    /// Wraps the first available adapter exposed by the platform manager.
+    pub async fn new() -> Result<Self, Box<dyn Error>> {
+        let manager = Manager::new().await?;
+        let adapter = manager
+            .adapters()
+            .await?
+            .into_iter()
+            .next()
+            .ok_or("No adapters found")?;
+        Ok(Self { adapter })
+    }
+
+    /// Wraps an already-selected adapter (see [`list_adapters`]).
+    pub fn with_adapter(adapter: Adapter) -> Self {
+        Self { adapter }
+    }
+
+    /// Resolves a peripheral by its string id.
+    async fn peripheral(
+        &self,
+        id: &str,
+    ) -> Result<btleplug::platform::Peripheral, Box<dyn Error>> {
+        for peripheral in self.adapter.peripherals().await? {
+            if peripheral.id().to_string() == id {
+                return Ok(peripheral);
+            }
+        }
+        Err(format!("Peripheral {id} not found").into())
+    }
+}
+
+#[async_trait]
+impl ScanBackend for BtleplugBackend {
+    async fn list_adapters(&self) -> Result<Vec<String>, Box<dyn Error>> {
+        list_adapters().await
+    }
+
+    async fn start_scan(&self, options: &ScanOptions) -> Result<(), Box<dyn Error>> {
+        let filter = ScanFilter {
+            services: options.services.clone(),
+        };
+        // If the controller is wedged, power-cycle it once and retry before failing.
+        if self.adapter.start_scan(filter).await.is_err() {
+            power_cycle(&self.adapter, options).await?;
+        }
+        Ok(())
+    }
+
+    async fn events(
+        &self,
+    ) -> Result<Pin<Box<dyn Stream<Item = ScanEvent> + Send>>, Box<dyn Error>> {
+        let events = self.adapter.events().await?;
+        let mapped = events.filter_map(|event| async move {
+            match event {
+                CentralEvent::DeviceDiscovered(id) => {
+                    Some(ScanEvent::DeviceDiscovered(id.to_string()))
+                }
+                CentralEvent::DeviceUpdated(id)
+                | CentralEvent::ManufacturerDataAdvertisement { id, .. }
+                | CentralEvent::ServiceDataAdvertisement { id, .. }
+                | CentralEvent::ServicesAdvertisement { id, .. } => {
+                    Some(ScanEvent::DeviceUpdated(id.to_string()))
+                }
+                CentralEvent::DeviceDisconnected(id) => {
+                    Some(ScanEvent::DeviceDisconnected(id.to_string()))
+                }
+                _ => None,
+            }
+        });
+        Ok(Box::pin(mapped))
+    }
+
+    async fn properties(&self, id: &str) -> Result<Option<PeripheralProperties>, Box<dyn Error>> {
+        Ok(self.peripheral(id).await?.properties().await?)
+    }
+
+    async fn connect(&self, id: &str) -> Result<(), Box<dyn Error>> {
+        let peripheral = self.peripheral(id).await?;
+        timeout(Duration::from_secs(10), peripheral.connect()).await??;
+        Ok(())
+    }
+
+    async fn characteristics(&self, id: &str) -> Result<Vec<Characteristic>, Box<dyn Error>> {
+        get_characteristics(&self.peripheral(id).await?).await
+    }
+
+    async fn peripheral_handle(&self, id: &str) -> Option<btleplug::platform::Peripheral> {
+        self.peripheral(id).await.ok()
+    }
+}
+
+/// A single fake device in a [`MockBackend`] data file.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MockDevice {
+    /// Stable identifier used as the device key.
+    pub id: String,
+    #[serde(default)]
+    pub local_name: Option<String>,
+    #[serde(default)]
+    pub rssi: Option<i16>,
+    #[serde(default)]
+    pub tx_power_level: Option<i16>,
+    #[serde(default)]
+    pub services: Vec<Uuid>,
+    #[serde(default)]
+    pub manufacturer_data: HashMap<u16, Vec<u8>>,
+    #[serde(default)]
+    pub characteristics: Vec<MockCharacteristic>,
+}
+
+/// A single fake characteristic in a [`MockDevice`]'s GATT tree.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MockCharacteristic {
+    pub uuid: Uuid,
+    pub service: Uuid,
+    /// Raw `CharPropFlags` bits (read/write/notify/…).
+    #[serde(default)]
+    pub property_bits: u8,
+    #[serde(default)]
+    pub descriptors: Vec<Uuid>,
+}
+
+/// [`ScanBackend`] that replays a fixed set of devices loaded from a JSON file,
+/// modelled on Servo's WebBluetooth `test` mock adapter.
+pub struct MockBackend {
+    devices: Vec<MockDevice>,
+}
+
+impl MockBackend {
+    /// Builds a mock from an in-memory device list.
+    pub fn new(devices: Vec<MockDevice>) -> Self {
+        Self { devices }
+    }
+
+    /// Loads the mock device list from a JSON data file.
+    pub fn from_file(path: impl AsRef<std::path::Path>) -> Result<Self, Box<dyn Error>> {
+        let data = std::fs::read_to_string(path)?;
+        let devices: Vec<MockDevice> = serde_json::from_str(&data)?;
+        Ok(Self::new(devices))
+    }
+
+    fn device(&self, id: &str) -> Result<&MockDevice, Box<dyn Error>> {
+        self.devices
+            .iter()
+            .find(|device| device.id == id)
+            .ok_or_else(|| format!("Peripheral {id} not found").into())
+    }
+}
+
+#[async_trait]
+impl ScanBackend for MockBackend {
+    async fn list_adapters(&self) -> Result<Vec<String>, Box<dyn Error>> {
+        Ok(vec!["mock0".to_string()])
+    }
+
+    async fn start_scan(&self, _options: &ScanOptions) -> Result<(), Box<dyn Error>> {
+        Ok(())
+    }
+
+    async fn events(
+        &self,
+    ) -> Result<Pin<Box<dyn Stream<Item = ScanEvent> + Send>>, Box<dyn Error>> {
+        let events: Vec<ScanEvent> = self
+            .devices
+            .iter()
+            .map(|device| ScanEvent::DeviceDiscovered(device.id.clone()))
+            .collect();
+        Ok(Box::pin(futures::stream::iter(events)))
+    }
+
+    async fn properties(&self, id: &str) -> Result<Option<PeripheralProperties>, Box<dyn Error>> {
+        let device = self.device(id)?;
+        Ok(Some(PeripheralProperties {
+            local_name: device.local_name.clone(),
+            rssi: device.rssi,
+            tx_power_level: device.tx_power_level,
+            services: device.services.clone(),
+            manufacturer_data: device.manufacturer_data.clone(),
+            ..Default::default()
+        }))
+    }
+
+    async fn connect(&self, id: &str) -> Result<(), Box<dyn Error>> {
+        self.device(id)?;
+        Ok(())
+    }
+
+    async fn characteristics(&self, id: &str) -> Result<Vec<Characteristic>, Box<dyn Error>> {
+        let device = self.device(id)?;
+        Ok(device
+            .characteristics
+            .iter()
+            .filter(|characteristic| {
+                !uuid_is_blocklisted(characteristic.uuid, Blocklist::All)
+                    && !uuid_is_blocklisted(characteristic.service, Blocklist::All)
+            })
+            .map(|characteristic| Characteristic {
+                uuid: characteristic.uuid,
+                properties: CharPropFlags::from_bits_truncate(characteristic.property_bits),
+                descriptors: characteristic.descriptors.clone(),
+                service: characteristic.service,
+            })
+            .collect())
+    }
+
+    async fn peripheral_handle(&self, _id: &str) -> Option<btleplug::platform::Peripheral> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mock_device(id: &str) -> MockDevice {
+        MockDevice {
+            id: id.to_string(),
+            local_name: None,
+            rssi: None,
+            tx_power_level: None,
+            services: Vec::new(),
+            manufacturer_data: HashMap::new(),
+            characteristics: Vec::new(),
+        }
+    }
+
+    async fn properties(backend: &MockBackend, id: &str) -> PeripheralProperties {
+        backend.properties(id).await.unwrap().unwrap()
+    }
+
+    #[tokio::test]
+    async fn filters_by_name_and_rssi() {
+        let mut near = mock_device("near");
+        near.local_name = Some("Sensor-01".to_string());
+        near.rssi = Some(-50);
+        let mut far = mock_device("far");
+        far.local_name = Some("Other".to_string());
+        far.rssi = Some(-90);
+        let backend = MockBackend::new(vec![near, far]);
+
+        let options = ScanOptions {
+            name_prefix: Some("Sensor".to_string()),
+            min_rssi: Some(-70),
+            ..ScanOptions::default()
+        };
+
+        assert!(options.matches(&properties(&backend, "near").await));
+        // Rejected on both the name prefix and the RSSI threshold.
+        assert!(!options.matches(&properties(&backend, "far").await));
+    }
+
+    #[tokio::test]
+    async fn filters_by_company_id() {
+        let mut matching = mock_device("matching");
+        matching.manufacturer_data = HashMap::from([(0x004c, vec![0x01])]);
+        let mut other = mock_device("other");
+        other.manufacturer_data = HashMap::from([(0x0059, vec![0x02])]);
+        let backend = MockBackend::new(vec![matching, other]);
+
+        let options = ScanOptions {
+            company_ids: vec![0x004c],
+            ..ScanOptions::default()
+        };
+
+        assert!(options.matches(&properties(&backend, "matching").await));
+        assert!(!options.matches(&properties(&backend, "other").await));
+    }
+
+    #[tokio::test]
+    async fn characteristics_hide_fully_blocklisted_attributes() {
+        let blocked: Uuid = "00002a03-0000-1000-8000-00805f9b34fb".parse().unwrap();
+        let allowed: Uuid = "00002a37-0000-1000-8000-00805f9b34fb".parse().unwrap();
+        let service: Uuid = "0000180d-0000-1000-8000-00805f9b34fb".parse().unwrap();
+        let mut device = mock_device("dev");
+        device.characteristics = vec![
+            MockCharacteristic {
+                uuid: blocked,
+                service,
+                property_bits: CharPropFlags::READ.bits(),
+                descriptors: Vec::new(),
+            },
+            MockCharacteristic {
+                uuid: allowed,
+                service,
+                property_bits: CharPropFlags::NOTIFY.bits(),
+                descriptors: Vec::new(),
+            },
+        ];
+        let backend = MockBackend::new(vec![device]);
+
+        let characteristics = backend.characteristics("dev").await.unwrap();
+        assert_eq!(characteristics.len(), 1);
+        assert_eq!(characteristics[0].uuid, allowed);
+    }
+
+    #[test]
+    fn blocklist_levels_are_access_specific() {
+        let reconnection_address: Uuid = "00002a03-0000-1000-8000-00805f9b34fb".parse().unwrap();
+        let ccc_descriptor: Uuid = "00002902-0000-1000-8000-00805f9b34fb".parse().unwrap();
+        let heart_rate: Uuid = "00002a37-0000-1000-8000-00805f9b34fb".parse().unwrap();
+
+        // `All` entries match every access level.
+        assert!(uuid_is_blocklisted(reconnection_address, Blocklist::Reads));
+        assert!(uuid_is_blocklisted(reconnection_address, Blocklist::Writes));
+        // A `Writes` entry only blocks writes.
+        assert!(uuid_is_blocklisted(ccc_descriptor, Blocklist::Writes));
+        assert!(!uuid_is_blocklisted(ccc_descriptor, Blocklist::Reads));
+        // Unlisted UUIDs are allowed for every access.
+        assert!(!uuid_is_blocklisted(heart_rate, Blocklist::Writes));
+    }
+
+    #[tokio::test]
+    async fn run_scan_reports_only_matching_devices() {
+        let mut keep = mock_device("keep");
+        keep.local_name = Some("Sensor-A".to_string());
+        keep.rssi = Some(-40);
+        let mut weak = mock_device("drop");
+        weak.local_name = Some("Sensor-B".to_string());
+        weak.rssi = Some(-95);
+        let backend = MockBackend::new(vec![keep, weak]);
+
+        let options = ScanOptions {
+            min_rssi: Some(-70),
+            ..ScanOptions::default()
+        };
+
+        let (tx, mut rx) = mpsc::channel(16);
+        let pause = Arc::new(AtomicBool::new(false));
+        run_scan(&backend, tx, pause, options).await.unwrap();
+
+        let mut last = Vec::new();
+        while let Ok(snapshot) = rx.try_recv() {
+            last = snapshot;
+        }
+        assert_eq!(last.len(), 1);
+        assert_eq!(last[0].id, "keep");
+        assert!(last[0].device.is_none());
+    }
+}
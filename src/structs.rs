@@ -0,0 +1,76 @@
+use crate::scan::ConnectionState;
+use btleplug::platform::Peripheral;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Advertising and connection information tracked for a single discovered
+/// device. Rows are refreshed in place as new advertisements arrive.
+#[derive(Debug, Clone)]
+pub struct DeviceInfo {
+    /// Platform identifier used as the map key during a scan.
+    pub id: String,
+    /// Advertised local name, if any.
+    pub name: Option<String>,
+    /// Advertised TX power level, in dBm.
+    pub tx_power: Option<i16>,
+    /// Device address.
+    pub address: String,
+    /// Most recently advertised RSSI, in dBm.
+    pub rssi: Option<i16>,
+    /// Manufacturer-specific data keyed by company identifier.
+    pub manufacturer_data: HashMap<u16, Vec<u8>>,
+    /// Advertised service UUIDs.
+    pub services: Vec<Uuid>,
+    /// Advertised service data keyed by service UUID.
+    pub service_data: HashMap<Uuid, Vec<u8>>,
+    /// Whether the device is currently reachable, mirroring the ACL state the
+    /// platform Bluetooth stack maintains.
+    pub connection_state: ConnectionState,
+    /// Handle to the underlying peripheral for GATT operations. `None` for
+    /// devices produced by a backend without live hardware (e.g. the mock).
+    pub device: Option<Peripheral>,
+}
+
+impl DeviceInfo {
+    /// Builds a `DeviceInfo` from a freshly received advertisement. A newly
+    /// discovered device is [`ConnectionState::Connected`] until a disconnect
+    /// event says otherwise.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: String,
+        name: Option<String>,
+        tx_power: Option<i16>,
+        address: String,
+        rssi: Option<i16>,
+        manufacturer_data: HashMap<u16, Vec<u8>>,
+        services: Vec<Uuid>,
+        service_data: HashMap<Uuid, Vec<u8>>,
+        device: Option<Peripheral>,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            tx_power,
+            address,
+            rssi,
+            manufacturer_data,
+            services,
+            service_data,
+            connection_state: ConnectionState::Connected,
+            device,
+        }
+    }
+}
+
+/// A GATT characteristic discovered on a connected device.
+#[derive(Debug, Clone)]
+pub struct Characteristic {
+    /// Characteristic UUID.
+    pub uuid: Uuid,
+    /// Declared property flags (read/write/notify/…).
+    pub properties: btleplug::api::CharPropFlags,
+    /// UUIDs of the characteristic's descriptors.
+    pub descriptors: Vec<Uuid>,
+    /// UUID of the service the characteristic belongs to.
+    pub service: Uuid,
+}